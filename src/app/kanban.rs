@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+/// A single card on a board.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Card {
+    pub id: u128,
+    pub name: String,
+    pub description: String,
+}
+
+/// A board holding an ordered list of cards.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Board {
+    pub id: u128,
+    pub name: String,
+    pub cards: Vec<Card>,
+}
+
+impl Default for Board {
+    fn default() -> Self {
+        Self {
+            id: 1,
+            name: "Default Board".to_string(),
+            cards: vec![],
+        }
+    }
+}
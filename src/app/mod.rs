@@ -0,0 +1,198 @@
+pub mod kanban;
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::Sender;
+
+use crate::inputs::keybindings::{Action, Chord, Context, KeybindConfig};
+use crate::io::cloud::{CardConflict, CloudConfig};
+use crate::io::workers::WorkerStatus;
+use crate::io::IoEvent;
+use kanban::Board;
+
+/// Shared application state, driven by the UI thread and mutated by the IO
+/// thread in response to `IoEvent`s.
+pub struct App {
+    io_tx: Sender<IoEvent>,
+    is_loading: bool,
+    pub boards: Vec<Board>,
+    pub keybinds: KeybindConfig,
+    worker_statuses: Vec<WorkerStatus>,
+    sync_conflicts: Vec<CardConflict>,
+    /// A crash recovery file `do_initialize` found on disk, along with the
+    /// boards parsed out of it, waiting on the user to accept or dismiss it.
+    pending_recovery: Option<(PathBuf, Vec<Board>)>,
+    should_quit: bool,
+    current_board_index: usize,
+}
+
+impl App {
+    pub fn new(io_tx: Sender<IoEvent>) -> Self {
+        Self {
+            io_tx,
+            is_loading: true,
+            boards: vec![Board::default()],
+            keybinds: KeybindConfig::default(),
+            worker_statuses: vec![],
+            sync_conflicts: vec![],
+            pending_recovery: None,
+            should_quit: false,
+            current_board_index: 0,
+        }
+    }
+
+    pub fn initialized(&mut self) {
+        self.is_loading = false;
+    }
+
+    pub fn loaded(&mut self) {
+        self.is_loading = false;
+    }
+
+    pub fn set_boards(&mut self, boards: Vec<Board>) {
+        self.boards = boards;
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.is_loading
+    }
+
+    pub fn set_worker_statuses(&mut self, worker_statuses: Vec<WorkerStatus>) {
+        self.worker_statuses = worker_statuses;
+    }
+
+    pub fn worker_statuses(&self) -> &[WorkerStatus] {
+        &self.worker_statuses
+    }
+
+    /// Records the cards a cloud sync couldn't merge automatically, so the UI
+    /// can prompt the user to resolve them.
+    pub fn set_sync_conflicts(&mut self, sync_conflicts: Vec<CardConflict>) {
+        self.sync_conflicts = sync_conflicts;
+    }
+
+    pub fn sync_conflicts(&self) -> &[CardConflict] {
+        &self.sync_conflicts
+    }
+
+    /// Records a crash recovery file found on startup, so the UI can offer to
+    /// restore it instead of silently discarding or silently applying it.
+    pub fn set_pending_recovery(&mut self, path: PathBuf, boards: Vec<Board>) {
+        self.pending_recovery = Some((path, boards));
+    }
+
+    pub fn pending_recovery(&self) -> Option<&(PathBuf, Vec<Board>)> {
+        self.pending_recovery.as_ref()
+    }
+
+    pub fn clear_pending_recovery(&mut self) {
+        self.pending_recovery = None;
+    }
+
+    pub fn should_quit(&self) -> bool {
+        self.should_quit
+    }
+
+    pub fn current_board_index(&self) -> usize {
+        self.current_board_index
+    }
+
+    /// Resolves `chord` against `self.keybinds` for `context` and, for the
+    /// actions `App` itself holds the state for, applies the effect directly.
+    /// This is the call site `KeybindConfig::resolve` is for: the UI's input
+    /// loop builds a `Chord` from the raw key event it receives and the
+    /// `Context` for whatever screen is focused, and calls this on every
+    /// keypress. Screen-level actions (menu navigation, moving a card within a
+    /// board) are handed back as the resolved `Action` for the UI layer to
+    /// apply against the cursor/screen state it owns.
+    pub fn dispatch(&mut self, context: Context, chord: Chord) -> Option<Action> {
+        let action = self.keybinds.resolve(context, chord)?;
+        match action {
+            Action::Quit => self.should_quit = true,
+            Action::SaveLocalData => {
+                let _ = self.io_tx.try_send(IoEvent::SaveLocalData);
+            }
+            Action::NextBoard => self.cycle_board(1),
+            Action::PrevBoard => self.cycle_board(self.boards.len().saturating_sub(1)),
+            Action::MoveCardUp
+            | Action::MoveCardDown
+            | Action::MoveCardLeft
+            | Action::MoveCardRight
+            | Action::OpenConfigMenu
+            | Action::GoToMainMenu => {}
+        }
+        Some(action)
+    }
+
+    fn cycle_board(&mut self, delta: usize) {
+        if self.boards.is_empty() {
+            return;
+        }
+        self.current_board_index = (self.current_board_index + delta) % self.boards.len();
+    }
+}
+
+/// Persisted, user-editable configuration, written as JSON into the config
+/// file managed by `io::handler::prepare_config_dir`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AppConfig {
+    pub keybinds: KeybindConfig,
+    /// How often (in seconds) the autosave worker checks whether `boards` is
+    /// dirty and, if so, persists it. Adjustable at runtime via
+    /// `IoEvent::SetAutosaveInterval`; this is only the value loaded at startup.
+    pub autosave_interval_seconds: u64,
+    /// Credentials for the S3-compatible cloud backend. `None` means cloud
+    /// sync is disabled, which is the default for a fresh install.
+    pub cloud: Option<CloudConfig>,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            keybinds: KeybindConfig::default(),
+            autosave_interval_seconds: 60,
+            cloud: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    fn test_app() -> App {
+        let (tx, _rx) = tokio::sync::mpsc::channel(1);
+        App::new(tx)
+    }
+
+    #[test]
+    fn quit_chord_sets_should_quit() {
+        let mut app = test_app();
+        let action = app.dispatch(Context::Home, (KeyModifiers::NONE, KeyCode::Char('q')));
+        assert_eq!(action, Some(Action::Quit));
+        assert!(app.should_quit());
+    }
+
+    #[test]
+    fn unbound_chord_resolves_to_nothing_and_has_no_effect() {
+        let mut app = test_app();
+        let action = app.dispatch(Context::Home, (KeyModifiers::NONE, KeyCode::Char('z')));
+        assert_eq!(action, None);
+        assert!(!app.should_quit());
+    }
+
+    #[test]
+    fn next_board_wraps_around() {
+        let mut app = test_app();
+        app.set_boards(vec![Board::default(), Board::default()]);
+        assert_eq!(app.current_board_index(), 0);
+
+        app.dispatch(Context::BoardView, (KeyModifiers::NONE, KeyCode::Tab));
+        assert_eq!(app.current_board_index(), 1);
+
+        app.dispatch(Context::BoardView, (KeyModifiers::NONE, KeyCode::Tab));
+        assert_eq!(app.current_board_index(), 0);
+    }
+}
@@ -0,0 +1,224 @@
+use std::collections::HashMap;
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+/// The logical area of the app a keypress should be resolved against. Mirrors the
+/// major screens/modes the UI can be in.
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug, Serialize, Deserialize)]
+pub enum Context {
+    Home,
+    BoardView,
+    CardEdit,
+    ConfigMenu,
+}
+
+/// Every rebindable action a chord can be mapped to.
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug, Serialize, Deserialize)]
+pub enum Action {
+    Quit,
+    SaveLocalData,
+    NextBoard,
+    PrevBoard,
+    MoveCardUp,
+    MoveCardDown,
+    MoveCardLeft,
+    MoveCardRight,
+    OpenConfigMenu,
+    GoToMainMenu,
+}
+
+/// A parsed keychord: the modifiers that must be held plus the key itself.
+pub type Chord = (KeyModifiers, KeyCode);
+
+/// The keybind table, as it lives in `AppConfig`: one chord-to-action map per `Context`.
+///
+/// The on-disk representation keys chords by their `"<...>"` string form (JSON/RON
+/// object keys must be strings), while `resolve` works against parsed `Chord`s built
+/// from that string at load time.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct KeybindConfig {
+    binds: HashMap<Context, HashMap<String, Action>>,
+    #[serde(skip)]
+    parsed: HashMap<Context, HashMap<Chord, Action>>,
+}
+
+impl KeybindConfig {
+    /// Parses the raw `"<chord>" -> Action` tables into `Chord -> Action` maps,
+    /// skipping (and warning about) any chord string that doesn't parse.
+    pub fn compile(mut self) -> Self {
+        let mut parsed: HashMap<Context, HashMap<Chord, Action>> = HashMap::new();
+        for (context, binds) in &self.binds {
+            let mut context_map = HashMap::new();
+            for (chord_str, action) in binds {
+                match parse_chord(chord_str) {
+                    Some(chord) => {
+                        context_map.insert(chord, *action);
+                    }
+                    None => warn!("Ignoring unrecognized keybind chord: {}", chord_str),
+                }
+            }
+            parsed.insert(*context, context_map);
+        }
+        self.parsed = parsed;
+        self
+    }
+
+    /// Looks up the action bound to `chord` within `context`, if any.
+    pub fn resolve(&self, context: Context, chord: Chord) -> Option<Action> {
+        self.parsed.get(&context)?.get(&chord).copied()
+    }
+}
+
+impl Default for KeybindConfig {
+    fn default() -> Self {
+        let mut binds: HashMap<Context, HashMap<String, Action>> = HashMap::new();
+
+        let mut home = HashMap::new();
+        home.insert("<q>".to_string(), Action::Quit);
+        home.insert("<Ctrl-c>".to_string(), Action::Quit);
+        home.insert("<Ctrl-s>".to_string(), Action::SaveLocalData);
+        binds.insert(Context::Home, home);
+
+        let mut board_view = HashMap::new();
+        board_view.insert("<q>".to_string(), Action::Quit);
+        board_view.insert("<Ctrl-c>".to_string(), Action::Quit);
+        board_view.insert("<Ctrl-s>".to_string(), Action::SaveLocalData);
+        board_view.insert("<tab>".to_string(), Action::NextBoard);
+        board_view.insert("<Shift-tab>".to_string(), Action::PrevBoard);
+        board_view.insert("<Ctrl-Up>".to_string(), Action::MoveCardUp);
+        board_view.insert("<Ctrl-Down>".to_string(), Action::MoveCardDown);
+        board_view.insert("<Ctrl-Left>".to_string(), Action::MoveCardLeft);
+        board_view.insert("<Ctrl-Right>".to_string(), Action::MoveCardRight);
+        board_view.insert("<c>".to_string(), Action::OpenConfigMenu);
+        binds.insert(Context::BoardView, board_view);
+
+        let mut card_edit = HashMap::new();
+        card_edit.insert("<esc>".to_string(), Action::GoToMainMenu);
+        binds.insert(Context::CardEdit, card_edit);
+
+        binds.insert(Context::ConfigMenu, HashMap::new());
+
+        KeybindConfig {
+            binds,
+            parsed: HashMap::new(),
+        }
+        .compile()
+    }
+}
+
+/// Parses a chord string of the form `<[Ctrl-][Alt-][Shift-]<key>>` into its
+/// modifiers and key code. Modifier names and named keys are matched
+/// case-insensitively; a single-character key token is case-sensitive (`<a>` and
+/// `<A>` are different chords).
+///
+/// Recognised key tokens: any single character, or one of `esc`, `tab`, `enter`,
+/// `backspace`, `space`, `up`, `down`, `left`, `right`, `home`, `end`, `pageup`,
+/// `pagedown`, `delete`, `insert`, or `f` followed by a number from 0 to 255
+/// (`f1`, `f12`, ...).
+pub fn parse_chord(chord: &str) -> Option<Chord> {
+    let inner = chord.strip_prefix('<')?.strip_suffix('>')?;
+    if inner.is_empty() {
+        return None;
+    }
+
+    let mut modifiers = KeyModifiers::NONE;
+    let mut rest = inner;
+    loop {
+        let lower_rest = rest.to_ascii_lowercase();
+        if let Some(tail) = lower_rest.strip_prefix("ctrl-") {
+            modifiers |= KeyModifiers::CONTROL;
+            rest = &rest[rest.len() - tail.len()..];
+        } else if let Some(tail) = lower_rest.strip_prefix("alt-") {
+            modifiers |= KeyModifiers::ALT;
+            rest = &rest[rest.len() - tail.len()..];
+        } else if let Some(tail) = lower_rest.strip_prefix("shift-") {
+            modifiers |= KeyModifiers::SHIFT;
+            rest = &rest[rest.len() - tail.len()..];
+        } else {
+            break;
+        }
+    }
+
+    let key_code = match rest.to_ascii_lowercase().as_str() {
+        "esc" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "enter" => KeyCode::Enter,
+        "backspace" => KeyCode::Backspace,
+        "space" => KeyCode::Char(' '),
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "delete" => KeyCode::Delete,
+        "insert" => KeyCode::Insert,
+        f if f.len() >= 2 && f.starts_with('f') && f[1..].parse::<u8>().is_ok() => {
+            KeyCode::F(f[1..].parse().ok()?)
+        }
+        _ if rest.chars().count() == 1 => KeyCode::Char(rest.chars().next()?),
+        _ => return None,
+    };
+
+    Some((modifiers, key_code))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_plain_char() {
+        assert_eq!(parse_chord("<q>"), Some((KeyModifiers::NONE, KeyCode::Char('q'))));
+    }
+
+    #[test]
+    fn parses_ctrl_modifier_case_insensitively() {
+        assert_eq!(
+            parse_chord("<ctrl-c>"),
+            Some((KeyModifiers::CONTROL, KeyCode::Char('c')))
+        );
+        assert_eq!(
+            parse_chord("<Ctrl-c>"),
+            Some((KeyModifiers::CONTROL, KeyCode::Char('c')))
+        );
+    }
+
+    #[test]
+    fn key_token_stays_case_sensitive() {
+        assert_eq!(
+            parse_chord("<Ctrl-C>"),
+            Some((KeyModifiers::CONTROL, KeyCode::Char('C')))
+        );
+    }
+
+    #[test]
+    fn parses_named_keys() {
+        assert_eq!(parse_chord("<esc>"), Some((KeyModifiers::NONE, KeyCode::Esc)));
+        assert_eq!(parse_chord("<f1>"), Some((KeyModifiers::NONE, KeyCode::F(1))));
+    }
+
+    #[test]
+    fn rejects_malformed_chords() {
+        assert_eq!(parse_chord("q"), None);
+        assert_eq!(parse_chord("<>"), None);
+        assert_eq!(parse_chord("<ctrl-nonsense>"), None);
+    }
+
+    #[test]
+    fn default_config_resolves_quit_in_multiple_contexts() {
+        let config = KeybindConfig::default();
+        assert_eq!(
+            config.resolve(Context::Home, (KeyModifiers::NONE, KeyCode::Char('q'))),
+            Some(Action::Quit)
+        );
+        assert_eq!(
+            config.resolve(Context::BoardView, (KeyModifiers::CONTROL, KeyCode::Char('c'))),
+            Some(Action::Quit)
+        );
+    }
+}
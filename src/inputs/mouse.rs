@@ -1,13 +1,37 @@
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
+use std::time::{Duration, Instant};
 
 use crossterm::event;
 
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug, Serialize, Deserialize)]
+pub enum MouseButton {
+    Left,
+    Right,
+    Middle,
+}
+
+impl From<event::MouseButton> for MouseButton {
+    fn from(button: event::MouseButton) -> Self {
+        match button {
+            event::MouseButton::Left => MouseButton::Left,
+            event::MouseButton::Right => MouseButton::Right,
+            event::MouseButton::Middle => MouseButton::Middle,
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Copy, Hash, Debug, Serialize, Deserialize)]
 pub enum Mouse {
     LeftPress,
     RightPress,
     MiddlePress,
+    /// A button held down while the cursor moves, carrying the column/row it
+    /// moved to. Used to pick up and follow a card while dragging it.
+    Drag(MouseButton, u16, u16),
+    /// The button released after one or more `Drag` events, i.e. a card dropped
+    /// onto whatever's under the cursor.
+    Drop(MouseButton, u16, u16),
     ScrollUp,
     ScrollDown,
     ScrollLeft,
@@ -26,6 +50,8 @@ impl Display for Mouse {
             Mouse::ScrollDown => write!(f, "<Mouse::ScrollDown>"),
             Mouse::ScrollLeft => write!(f, "<Mouse::Ctrl + ScrollUp>"),
             Mouse::ScrollRight => write!(f, "<Mouse::Ctrl + ScrollDown>"),
+            Mouse::Drag(button, x, y) => write!(f, "<Mouse::Drag({:?}, {}, {})>", button, x, y),
+            Mouse::Drop(button, x, y) => write!(f, "<Mouse::Drop({:?}, {}, {})>", button, x, y),
             Mouse::Move(x, y) => write!(f, "<Mouse::Move({}, {})>", x, y),
             Mouse::Unknown => write!(f, "<Mouse::Unknown>"),
         }
@@ -65,6 +91,12 @@ impl From<event::MouseEvent> for Mouse {
                 kind: event::MouseEventKind::ScrollDown,
                 ..
             } => Mouse::ScrollDown,
+            event::MouseEvent {
+                kind: event::MouseEventKind::Drag(button),
+                column,
+                row,
+                ..
+            } => Mouse::Drag(button.into(), column, row),
             event::MouseEvent {
                 kind: event::MouseEventKind::Moved,
                 column,
@@ -74,4 +106,259 @@ impl From<event::MouseEvent> for Mouse {
             _ => Mouse::Unknown,
         }
     }
+}
+
+/// How long a run of same-direction scroll ticks may be coalesced into a single
+/// accumulated movement before it's treated as a fresh gesture.
+const SCROLL_COALESCE_WINDOW: Duration = Duration::from_millis(120);
+
+/// Tracks stateful mouse gestures that a single `MouseEvent` can't express on its
+/// own: a press/drag/release sequence (so cards can be picked up and dropped),
+/// and a run of scroll ticks coalesced into one accumulated movement.
+pub struct MouseGestureTracker {
+    dragging: Option<MouseButton>,
+    scroll_accumulator: i32,
+    scroll_direction_up: Option<bool>,
+    last_scroll_at: Option<Instant>,
+}
+
+impl MouseGestureTracker {
+    pub fn new() -> Self {
+        Self {
+            dragging: None,
+            scroll_accumulator: 0,
+            scroll_direction_up: None,
+            last_scroll_at: None,
+        }
+    }
+
+    /// Feeds one raw terminal mouse event through the gesture tracker, returning
+    /// zero or more logical `Mouse` events. A plain press/move/click yields
+    /// exactly one event; a coalesced scroll run may yield several at once (one
+    /// per accumulated line) once the coalescing window lapses.
+    pub fn process(&mut self, event: event::MouseEvent) -> Vec<Mouse> {
+        match event.kind {
+            event::MouseEventKind::Down(_) => vec![Mouse::from(event)],
+            event::MouseEventKind::Drag(button) => {
+                // Only a left-button drag picks up a card; a plain click or a
+                // right/middle press+release shouldn't be misread downstream as a drop.
+                let button = MouseButton::from(button);
+                if button == MouseButton::Left {
+                    self.dragging = Some(button);
+                }
+                vec![Mouse::Drag(button, event.column, event.row)]
+            }
+            event::MouseEventKind::Up(button) => match self.dragging.take() {
+                // A release after at least one `Drag` is a drop, regardless of
+                // whether the release coordinates match the last drag position.
+                Some(_) => vec![Mouse::Drop(button.into(), event.column, event.row)],
+                None => vec![],
+            },
+            event::MouseEventKind::ScrollUp | event::MouseEventKind::ScrollDown
+                if event.modifiers != event::KeyModifiers::CONTROL =>
+            {
+                self.accumulate_scroll(matches!(event.kind, event::MouseEventKind::ScrollUp))
+            }
+            _ => vec![Mouse::from(event)],
+        }
+    }
+
+    fn accumulate_scroll(&mut self, is_up: bool) -> Vec<Mouse> {
+        let now = Instant::now();
+        // Only a *previous* tick going stale counts as the window lapsing — the
+        // very first tick of a gesture has no prior tick to compare against, so
+        // it must not immediately flush itself back out.
+        let window_lapsed = self
+            .last_scroll_at
+            .is_some_and(|at| now.duration_since(at) > SCROLL_COALESCE_WINDOW);
+        let direction_flipped = self.scroll_direction_up.is_some_and(|prev| prev != is_up);
+
+        let mut flushed = if window_lapsed || direction_flipped {
+            self.flush_scroll()
+        } else {
+            vec![]
+        };
+
+        self.scroll_direction_up = Some(is_up);
+        self.last_scroll_at = Some(now);
+        self.scroll_accumulator += if is_up { 1 } else { -1 };
+
+        // Flush immediately once the window is stale so held scrolling still
+        // feels responsive instead of waiting for a direction change.
+        if window_lapsed {
+            flushed.extend(self.flush_scroll());
+        }
+        flushed
+    }
+
+    /// Flushes a pending scroll run that's gone stale without a follow-up
+    /// event to trigger the reactive check in `accumulate_scroll` — the case
+    /// of a single, isolated wheel notch on a non-trackpad mouse, which would
+    /// otherwise sit in the accumulator forever. Meant to be polled on a timer
+    /// (e.g. once per render tick) by whatever owns this tracker, independent
+    /// of whether another input event ever arrives.
+    pub fn flush_idle(&mut self) -> Vec<Mouse> {
+        let is_stale = self
+            .last_scroll_at
+            .is_some_and(|at| Instant::now().duration_since(at) > SCROLL_COALESCE_WINDOW);
+        if is_stale {
+            self.flush_scroll()
+        } else {
+            vec![]
+        }
+    }
+
+    fn flush_scroll(&mut self) -> Vec<Mouse> {
+        let count = self.scroll_accumulator;
+        self.scroll_accumulator = 0;
+        let event = if count > 0 {
+            Mouse::ScrollUp
+        } else if count < 0 {
+            Mouse::ScrollDown
+        } else {
+            return vec![];
+        };
+        vec![event; count.unsigned_abs() as usize]
+    }
+}
+
+impl Default for MouseGestureTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scroll_event(kind: event::MouseEventKind) -> event::MouseEvent {
+        event::MouseEvent {
+            kind,
+            column: 0,
+            row: 0,
+            modifiers: event::KeyModifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn drag_then_release_emits_drop() {
+        let mut tracker = MouseGestureTracker::new();
+        let down = event::MouseEvent {
+            kind: event::MouseEventKind::Down(event::MouseButton::Left),
+            column: 1,
+            row: 1,
+            modifiers: event::KeyModifiers::NONE,
+        };
+        assert_eq!(tracker.process(down), vec![Mouse::LeftPress]);
+
+        let drag = event::MouseEvent {
+            kind: event::MouseEventKind::Drag(event::MouseButton::Left),
+            column: 5,
+            row: 2,
+            modifiers: event::KeyModifiers::NONE,
+        };
+        assert_eq!(
+            tracker.process(drag),
+            vec![Mouse::Drag(MouseButton::Left, 5, 2)]
+        );
+
+        let up = event::MouseEvent {
+            kind: event::MouseEventKind::Up(event::MouseButton::Left),
+            column: 9,
+            row: 3,
+            modifiers: event::KeyModifiers::NONE,
+        };
+        assert_eq!(
+            tracker.process(up),
+            vec![Mouse::Drop(MouseButton::Left, 9, 3)]
+        );
+    }
+
+    #[test]
+    fn plain_click_without_drag_emits_no_drop() {
+        let mut tracker = MouseGestureTracker::new();
+        let down = event::MouseEvent {
+            kind: event::MouseEventKind::Down(event::MouseButton::Left),
+            column: 1,
+            row: 1,
+            modifiers: event::KeyModifiers::NONE,
+        };
+        tracker.process(down);
+
+        let up = event::MouseEvent {
+            kind: event::MouseEventKind::Up(event::MouseButton::Left),
+            column: 1,
+            row: 1,
+            modifiers: event::KeyModifiers::NONE,
+        };
+        assert_eq!(tracker.process(up), Vec::<Mouse>::new());
+    }
+
+    #[test]
+    fn rapid_same_direction_scrolls_stay_pending_until_flushed() {
+        let mut tracker = MouseGestureTracker::new();
+        assert_eq!(
+            tracker.process(scroll_event(event::MouseEventKind::ScrollUp)),
+            Vec::<Mouse>::new()
+        );
+        assert_eq!(
+            tracker.process(scroll_event(event::MouseEventKind::ScrollUp)),
+            Vec::<Mouse>::new()
+        );
+        assert_eq!(tracker.scroll_accumulator, 2);
+    }
+
+    #[test]
+    fn direction_flip_flushes_and_resets_accumulator() {
+        let mut tracker = MouseGestureTracker::new();
+        tracker.process(scroll_event(event::MouseEventKind::ScrollUp));
+        tracker.process(scroll_event(event::MouseEventKind::ScrollUp));
+        let flushed = tracker.process(scroll_event(event::MouseEventKind::ScrollDown));
+        assert_eq!(flushed, vec![Mouse::ScrollUp, Mouse::ScrollUp]);
+        assert_eq!(tracker.scroll_accumulator, -1);
+    }
+
+    #[test]
+    fn idle_flush_emits_a_lone_scroll_tick_without_a_second_event() {
+        let mut tracker = MouseGestureTracker::new();
+        tracker.process(scroll_event(event::MouseEventKind::ScrollUp));
+        assert_eq!(tracker.flush_idle(), Vec::<Mouse>::new());
+
+        std::thread::sleep(SCROLL_COALESCE_WINDOW + Duration::from_millis(20));
+        assert_eq!(tracker.flush_idle(), vec![Mouse::ScrollUp]);
+    }
+
+    #[test]
+    fn non_left_press_does_not_start_drag_tracking() {
+        let mut tracker = MouseGestureTracker::new();
+        let down = event::MouseEvent {
+            kind: event::MouseEventKind::Down(event::MouseButton::Right),
+            column: 1,
+            row: 1,
+            modifiers: event::KeyModifiers::NONE,
+        };
+        tracker.process(down);
+
+        let up = event::MouseEvent {
+            kind: event::MouseEventKind::Up(event::MouseButton::Right),
+            column: 1,
+            row: 1,
+            modifiers: event::KeyModifiers::NONE,
+        };
+        assert_eq!(tracker.process(up), Vec::<Mouse>::new());
+    }
+
+    #[test]
+    fn ctrl_scroll_maps_to_horizontal_without_accumulating() {
+        let mut tracker = MouseGestureTracker::new();
+        let event = event::MouseEvent {
+            kind: event::MouseEventKind::ScrollUp,
+            column: 0,
+            row: 0,
+            modifiers: event::KeyModifiers::CONTROL,
+        };
+        assert_eq!(tracker.process(event), vec![Mouse::ScrollRight]);
+        assert_eq!(tracker.scroll_accumulator, 0);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,115 @@
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+type HmacSha256 = Hmac<Sha256>;
+
+fn sha256_hex(data: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    hex::encode(hasher.finalize())
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts a key of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Credentials and scope needed to sign a request for an S3-compatible API.
+pub struct SigV4Credentials<'a> {
+    pub access_key: &'a str,
+    pub secret_key: &'a str,
+    pub region: &'a str,
+}
+
+/// The headers a SigV4-signed request must carry, computed for this one request.
+pub struct SignedHeaders {
+    pub authorization: String,
+    pub x_amz_date: String,
+    pub x_amz_content_sha256: String,
+}
+
+/// Computes the `Authorization`/`x-amz-date`/`x-amz-content-sha256` headers for
+/// a request against an S3-compatible API, per AWS's SigV4 signing process.
+/// `canonical_uri` is the path-style request path (e.g. `/bucket/key`) and
+/// `canonical_querystring` must already be in `key=value&...` form, sorted by key.
+pub fn sign(
+    creds: &SigV4Credentials,
+    method: &str,
+    host: &str,
+    canonical_uri: &str,
+    canonical_querystring: &str,
+    payload: &[u8],
+) -> SignedHeaders {
+    const SERVICE: &str = "s3";
+
+    let now = Utc::now();
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let date_stamp = now.format("%Y%m%d").to_string();
+    let payload_hash = sha256_hex(payload);
+
+    let canonical_headers = format!(
+        "host:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n",
+        host, payload_hash, amz_date
+    );
+    let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+
+    let canonical_request = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}",
+        method, canonical_uri, canonical_querystring, canonical_headers, signed_headers, payload_hash
+    );
+
+    let credential_scope = format!(
+        "{}/{}/{}/aws4_request",
+        date_stamp, creds.region, SERVICE
+    );
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+        amz_date,
+        credential_scope,
+        sha256_hex(canonical_request.as_bytes())
+    );
+
+    let k_date = hmac_sha256(
+        format!("AWS4{}", creds.secret_key).as_bytes(),
+        date_stamp.as_bytes(),
+    );
+    let k_region = hmac_sha256(&k_date, creds.region.as_bytes());
+    let k_service = hmac_sha256(&k_region, SERVICE.as_bytes());
+    let k_signing = hmac_sha256(&k_service, b"aws4_request");
+    let signature = hex::encode(hmac_sha256(&k_signing, string_to_sign.as_bytes()));
+
+    let authorization = format!(
+        "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+        creds.access_key, credential_scope, signed_headers, signature
+    );
+
+    SignedHeaders {
+        authorization,
+        x_amz_date: amz_date,
+        x_amz_content_sha256: payload_hash,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signing_produces_a_well_formed_authorization_header() {
+        let creds = SigV4Credentials {
+            access_key: "AKIDEXAMPLE",
+            secret_key: "wJalrXUtnFEMI/K7MDENG/bPxRfiCYEXAMPLEKEY",
+            region: "us-east-1",
+        };
+        let a = sign(&creds, "GET", "examplebucket.s3.amazonaws.com", "/test.txt", "", b"");
+        let b = sign(&creds, "GET", "examplebucket.s3.amazonaws.com", "/test.txt", "", b"");
+        // Not byte-identical (timestamps may straddle a second boundary), but
+        // both must at least produce a well-formed SigV4 authorization header.
+        for header in [a.authorization, b.authorization] {
+            assert!(header.starts_with("AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/"));
+            assert!(header.contains("SignedHeaders=host;x-amz-content-sha256;x-amz-date"));
+        }
+    }
+}
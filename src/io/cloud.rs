@@ -0,0 +1,503 @@
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use eyre::Result;
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+
+use crate::app::kanban::{Board, Card};
+use crate::app::AppConfig;
+use crate::io::aws_sigv4::{sign, SigV4Credentials};
+use crate::io::handler::get_config_dir;
+
+const LAST_SYNCED_VERSION_FILE: &str = "last_synced_version";
+/// The full board state as of the last successful sync, kept alongside the
+/// version marker so a later sync can tell "removed since we last agreed" apart
+/// from "never existed on this side" — a version number alone can't.
+const LAST_SYNCED_STATE_FILE: &str = "last_synced_state.json";
+
+/// Credentials and endpoint for the S3-compatible object store boards are synced
+/// to/from. Lives on `AppConfig` alongside the local save settings.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CloudConfig {
+    pub endpoint: String,
+    pub region: String,
+    pub bucket: String,
+    pub access_key: String,
+    pub secret_key: String,
+}
+
+/// The result of pulling from the cloud: either a clean set of boards, or a set
+/// of conflicts that need the user to resolve them before anything is applied.
+pub enum SyncResult {
+    Clean(Vec<Board>),
+    Conflicted(Vec<Board>, Vec<CardConflict>),
+}
+
+/// A single card that was edited both locally and remotely since the last sync.
+pub struct CardConflict {
+    pub local: Card,
+    pub remote: Card,
+}
+
+/// Uploads `boards` as object `v{version}` in the configured bucket.
+pub async fn save_cloud_boards(config: &CloudConfig, version: u32, boards: &[Board]) -> Result<()> {
+    let key = format!("v{}", version);
+    let body = serde_json::to_vec(boards)?;
+    match put_object(config, &key, &body).await {
+        Ok(_) => {
+            info!("👍 Uploaded boards to cloud as {}", key);
+            Ok(())
+        }
+        Err(err) => {
+            error!("Cannot upload boards to cloud: {:?}", err);
+            Err(err)
+        }
+    }
+}
+
+/// Downloads the highest-versioned object in the bucket and reconciles it against
+/// `local_boards` (currently at `local_version`) using the last-synced marker
+/// recorded in the config dir.
+///
+/// If neither side advanced past the last sync, or only one side did, the result
+/// is `Clean`. If both advanced, boards and cards are merged by ID: additions and
+/// removals that don't overlap are merged automatically, while a card edited on
+/// both sides comes back as a `CardConflict` for the user to resolve.
+pub async fn get_cloud_boards(
+    config: &CloudConfig,
+    local_version: u32,
+    local_boards: &[Board],
+) -> Result<SyncResult> {
+    let remote_versions = list_object_versions(config).await?;
+    let highest_remote = match remote_versions.iter().max() {
+        Some(v) => *v,
+        None => return Ok(SyncResult::Clean(local_boards.to_vec())),
+    };
+
+    let remote_bytes = get_object(config, &format!("v{}", highest_remote)).await?;
+    let remote_boards: Vec<Board> = serde_json::from_slice(&remote_bytes)?;
+
+    let last_synced = read_last_synced_version().unwrap_or(0);
+    let remote_advanced = highest_remote > last_synced;
+    let local_advanced = local_version > last_synced;
+
+    if !remote_advanced {
+        return Ok(SyncResult::Clean(local_boards.to_vec()));
+    }
+    if !local_advanced {
+        write_last_synced_version(highest_remote)?;
+        write_last_synced_state(&remote_boards)?;
+        return Ok(SyncResult::Clean(remote_boards));
+    }
+
+    let base = read_last_synced_state();
+    let (merged, conflicts) = merge_boards(&base, local_boards, &remote_boards);
+    if conflicts.is_empty() {
+        write_last_synced_version(highest_remote)?;
+        write_last_synced_state(&merged)?;
+        Ok(SyncResult::Clean(merged))
+    } else {
+        Ok(SyncResult::Conflicted(merged, conflicts))
+    }
+}
+
+/// Merges two board sets against `base`, the state both sides last agreed on,
+/// so that an ID missing relative to `base` can be recognized as a removal
+/// instead of being silently ignored (a plain `local`-vs-`remote` union can't
+/// tell "the other side deleted this" apart from "this was never there").
+///
+/// IDs added on only one side since `base` are kept; IDs removed on one side
+/// and left untouched on the other are dropped, honoring the removal; IDs
+/// edited on one side while removed on the other keep the edit, since
+/// silently discarding someone's edit is worse than silently un-deleting
+/// something. IDs edited on both sides fall through to `merge_cards`, which
+/// applies the same base-aware logic one level down and reports genuine
+/// per-card conflicts.
+fn merge_boards(base: &[Board], local: &[Board], remote: &[Board]) -> (Vec<Board>, Vec<CardConflict>) {
+    let base_by_id: HashMap<_, _> = base.iter().map(|b| (b.id, b)).collect();
+    let local_by_id: HashMap<_, _> = local.iter().map(|b| (b.id, b)).collect();
+    let remote_by_id: HashMap<_, _> = remote.iter().map(|b| (b.id, b)).collect();
+
+    let all_ids: HashSet<_> = base_by_id
+        .keys()
+        .chain(local_by_id.keys())
+        .chain(remote_by_id.keys())
+        .copied()
+        .collect();
+
+    let mut merged = HashMap::new();
+    let mut conflicts = Vec::new();
+
+    for id in all_ids {
+        let base_board = base_by_id.get(&id).copied();
+        let local_board = local_by_id.get(&id).copied();
+        let remote_board = remote_by_id.get(&id).copied();
+
+        let resolved = match (base_board, local_board, remote_board) {
+            (_, None, None) => None,
+            (None, Some(l), None) => Some(l.clone()),
+            (None, None, Some(r)) => Some(r.clone()),
+            (_, Some(l), Some(r)) if l == r => Some(l.clone()),
+            (None, Some(l), Some(r)) => {
+                // Both sides independently created the same ID with different
+                // content (no shared ancestor to diff against) — merge their
+                // cards against an empty base so per-card conflicts surface.
+                let (merged_cards, card_conflicts) = merge_cards(&[], &l.cards, &r.cards);
+                conflicts.extend(card_conflicts);
+                let mut merged_board = l.clone();
+                merged_board.cards = merged_cards;
+                Some(merged_board)
+            }
+            (Some(b), Some(l), None) => {
+                if l == b {
+                    None // remote deleted it, local left it untouched: honor the deletion
+                } else {
+                    Some(l.clone()) // local edited it, remote deleted it: keep the edit
+                }
+            }
+            (Some(b), None, Some(r)) => {
+                if r == b {
+                    None // local deleted it, remote left it untouched: honor the deletion
+                } else {
+                    Some(r.clone()) // remote edited it, local deleted it: keep the edit
+                }
+            }
+            (Some(_), None, None) => None, // deleted on both sides
+            (Some(b), Some(l), Some(r)) => {
+                let (merged_cards, card_conflicts) = merge_cards(&b.cards, &l.cards, &r.cards);
+                conflicts.extend(card_conflicts);
+                let mut merged_board = l.clone();
+                merged_board.cards = merged_cards;
+                Some(merged_board)
+            }
+        };
+
+        if let Some(board) = resolved {
+            merged.insert(id, board);
+        }
+    }
+
+    (merged.into_values().collect(), conflicts)
+}
+
+/// Same base-aware removal-vs-ignorance logic as `merge_boards`, one level down.
+fn merge_cards(base: &[Card], local: &[Card], remote: &[Card]) -> (Vec<Card>, Vec<CardConflict>) {
+    let base_by_id: HashMap<_, _> = base.iter().map(|c| (c.id, c)).collect();
+    let local_by_id: HashMap<_, _> = local.iter().map(|c| (c.id, c)).collect();
+    let remote_by_id: HashMap<_, _> = remote.iter().map(|c| (c.id, c)).collect();
+
+    let all_ids: HashSet<_> = base_by_id
+        .keys()
+        .chain(local_by_id.keys())
+        .chain(remote_by_id.keys())
+        .copied()
+        .collect();
+
+    let mut merged = HashMap::new();
+    let mut conflicts = Vec::new();
+
+    for id in all_ids {
+        let base_card = base_by_id.get(&id).copied();
+        let local_card = local_by_id.get(&id).copied();
+        let remote_card = remote_by_id.get(&id).copied();
+
+        let resolved = match (base_card, local_card, remote_card) {
+            (_, None, None) => None,
+            (None, Some(l), None) => Some(l.clone()),
+            (None, None, Some(r)) => Some(r.clone()),
+            (_, Some(l), Some(r)) if l == r => Some(l.clone()),
+            (None, Some(l), Some(r)) => {
+                conflicts.push(CardConflict {
+                    local: l.clone(),
+                    remote: r.clone(),
+                });
+                continue;
+            }
+            (Some(b), Some(l), None) => {
+                if l == b {
+                    None // remote deleted it, local left it untouched: honor the deletion
+                } else {
+                    Some(l.clone()) // local edited it, remote deleted it: keep the edit
+                }
+            }
+            (Some(b), None, Some(r)) => {
+                if r == b {
+                    None // local deleted it, remote left it untouched: honor the deletion
+                } else {
+                    Some(r.clone()) // remote edited it, local deleted it: keep the edit
+                }
+            }
+            (Some(_), None, None) => None, // deleted on both sides
+            (Some(b), Some(l), Some(r)) if l == b => Some(r.clone()), // only remote edited it
+            (Some(b), Some(l), Some(r)) if r == b => Some(l.clone()), // only local edited it
+            (Some(_), Some(l), Some(r)) => {
+                conflicts.push(CardConflict {
+                    local: l.clone(),
+                    remote: r.clone(),
+                });
+                continue;
+            }
+        };
+
+        if let Some(card) = resolved {
+            merged.insert(id, card);
+        }
+    }
+
+    (merged.into_values().collect(), conflicts)
+}
+
+fn last_synced_version_path() -> PathBuf {
+    let mut path = get_config_dir();
+    path.push(LAST_SYNCED_VERSION_FILE);
+    path
+}
+
+fn read_last_synced_version() -> Option<u32> {
+    std::fs::read_to_string(last_synced_version_path())
+        .ok()
+        .and_then(|s| s.trim().parse().ok())
+}
+
+fn write_last_synced_version(version: u32) -> Result<()> {
+    std::fs::write(last_synced_version_path(), version.to_string())?;
+    Ok(())
+}
+
+fn last_synced_state_path() -> PathBuf {
+    let mut path = get_config_dir();
+    path.push(LAST_SYNCED_STATE_FILE);
+    path
+}
+
+/// The board state as of the last successful sync, or `[]` if there hasn't
+/// been one yet (in which case every ID on either side is treated as new,
+/// same as a plain union would).
+fn read_last_synced_state() -> Vec<Board> {
+    std::fs::read_to_string(last_synced_state_path())
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn write_last_synced_state(boards: &[Board]) -> Result<()> {
+    std::fs::write(last_synced_state_path(), serde_json::to_string(boards)?)?;
+    Ok(())
+}
+
+fn signed_headers(
+    config: &CloudConfig,
+    method: &str,
+    host: &str,
+    canonical_uri: &str,
+    canonical_querystring: &str,
+    body: &[u8],
+) -> crate::io::aws_sigv4::SignedHeaders {
+    let creds = SigV4Credentials {
+        access_key: &config.access_key,
+        secret_key: &config.secret_key,
+        region: &config.region,
+    };
+    sign(&creds, method, host, canonical_uri, canonical_querystring, body)
+}
+
+/// Extracts the endpoint's host (no scheme, no path) for use in the SigV4
+/// `host` header and canonical request.
+fn endpoint_host(config: &CloudConfig) -> Result<String> {
+    let url = url::Url::parse(&config.endpoint)?;
+    url.host_str()
+        .map(|host| match url.port() {
+            Some(port) => format!("{}:{}", host, port),
+            None => host.to_string(),
+        })
+        .ok_or_else(|| eyre::eyre!("cloud endpoint {} has no host", config.endpoint))
+}
+
+async fn put_object(config: &CloudConfig, key: &str, body: &[u8]) -> Result<()> {
+    let host = endpoint_host(config)?;
+    let canonical_uri = format!("/{}/{}", config.bucket, key);
+    let headers = signed_headers(config, "PUT", &host, &canonical_uri, "", body);
+
+    let client = reqwest::Client::new();
+    let url = format!("{}{}", config.endpoint, canonical_uri);
+    client
+        .put(url)
+        .header("host", host)
+        .header("x-amz-date", headers.x_amz_date)
+        .header("x-amz-content-sha256", headers.x_amz_content_sha256)
+        .header("authorization", headers.authorization)
+        .body(body.to_vec())
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
+
+async fn get_object(config: &CloudConfig, key: &str) -> Result<Vec<u8>> {
+    let host = endpoint_host(config)?;
+    let canonical_uri = format!("/{}/{}", config.bucket, key);
+    let headers = signed_headers(config, "GET", &host, &canonical_uri, "", b"");
+
+    let client = reqwest::Client::new();
+    let url = format!("{}{}", config.endpoint, canonical_uri);
+    let bytes = client
+        .get(url)
+        .header("host", host)
+        .header("x-amz-date", headers.x_amz_date)
+        .header("x-amz-content-sha256", headers.x_amz_content_sha256)
+        .header("authorization", headers.authorization)
+        .send()
+        .await?
+        .error_for_status()?
+        .bytes()
+        .await?;
+    Ok(bytes.to_vec())
+}
+
+async fn list_object_versions(config: &CloudConfig) -> Result<Vec<u32>> {
+    let host = endpoint_host(config)?;
+    let canonical_uri = format!("/{}", config.bucket);
+    let canonical_querystring = "list-type=2";
+    let headers = signed_headers(config, "GET", &host, &canonical_uri, canonical_querystring, b"");
+
+    let client = reqwest::Client::new();
+    let url = format!("{}{}?{}", config.endpoint, canonical_uri, canonical_querystring);
+    let body = client
+        .get(url)
+        .header("host", host)
+        .header("x-amz-date", headers.x_amz_date)
+        .header("x-amz-content-sha256", headers.x_amz_content_sha256)
+        .header("authorization", headers.authorization)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+
+    Ok(parse_object_keys(&body)?
+        .iter()
+        .filter_map(|key| key.strip_prefix('v'))
+        .filter_map(|v| v.parse().ok())
+        .collect())
+}
+
+/// Extracts every `<Key>` element's text content out of a `ListObjectsV2` XML
+/// response body.
+fn parse_object_keys(xml: &str) -> Result<Vec<String>> {
+    use quick_xml::events::Event;
+    use quick_xml::Reader;
+
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+    let mut buf = Vec::new();
+    let mut keys = Vec::new();
+    let mut in_key = false;
+
+    loop {
+        match reader.read_event_into(&mut buf)? {
+            Event::Start(tag) if tag.name().as_ref() == b"Key" => in_key = true,
+            Event::End(tag) if tag.name().as_ref() == b"Key" => in_key = false,
+            Event::Text(text) if in_key => keys.push(text.unescape()?.into_owned()),
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(keys)
+}
+
+pub fn cloud_config(app_config: &AppConfig) -> Option<&CloudConfig> {
+    app_config.cloud.as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(id: u128, description: &str) -> Card {
+        Card {
+            id,
+            name: format!("card-{}", id),
+            description: description.to_string(),
+        }
+    }
+
+    #[test]
+    fn local_deletion_is_not_resurrected_by_an_unrelated_remote_sync() {
+        let base = vec![card(1, "a"), card(2, "b")];
+        let local = vec![card(1, "a")]; // card 2 deleted locally, card 1 untouched
+        let remote = vec![card(1, "a"), card(2, "b")]; // remote unchanged since base
+
+        let (merged, conflicts) = merge_cards(&base, &local, &remote);
+        assert!(conflicts.is_empty());
+        assert_eq!(merged, vec![card(1, "a")]);
+    }
+
+    #[test]
+    fn remote_deletion_is_honored_when_local_left_it_untouched() {
+        let base = vec![card(1, "a"), card(2, "b")];
+        let local = vec![card(1, "a"), card(2, "b")]; // local unchanged since base
+        let remote = vec![card(1, "a")]; // card 2 deleted remotely
+
+        let (merged, conflicts) = merge_cards(&base, &local, &remote);
+        assert!(conflicts.is_empty());
+        assert_eq!(merged, vec![card(1, "a")]);
+    }
+
+    #[test]
+    fn an_edit_on_one_side_beats_a_delete_on_the_other() {
+        let base = vec![card(1, "a")];
+        let local = vec![card(1, "a-edited")];
+        let remote: Vec<Card> = vec![]; // deleted remotely
+
+        let (merged, conflicts) = merge_cards(&base, &local, &remote);
+        assert!(conflicts.is_empty());
+        assert_eq!(merged, vec![card(1, "a-edited")]);
+    }
+
+    #[test]
+    fn concurrent_edits_to_the_same_card_still_conflict() {
+        let base = vec![card(1, "a")];
+        let local = vec![card(1, "a-local")];
+        let remote = vec![card(1, "a-remote")];
+
+        let (merged, conflicts) = merge_cards(&base, &local, &remote);
+        assert!(merged.is_empty());
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].local, card(1, "a-local"));
+        assert_eq!(conflicts[0].remote, card(1, "a-remote"));
+    }
+
+    #[test]
+    fn deletion_on_both_sides_is_just_gone() {
+        let base = vec![card(1, "a")];
+        let local: Vec<Card> = vec![];
+        let remote: Vec<Card> = vec![];
+
+        let (merged, conflicts) = merge_cards(&base, &local, &remote);
+        assert!(merged.is_empty());
+        assert!(conflicts.is_empty());
+    }
+
+    #[test]
+    fn board_level_removal_uses_the_same_base_aware_logic() {
+        let base = vec![Board {
+            id: 1,
+            name: "Board".to_string(),
+            cards: vec![card(1, "a"), card(2, "b")],
+        }];
+        let local = vec![Board {
+            id: 1,
+            name: "Board".to_string(),
+            cards: vec![card(1, "a")], // card 2 deleted locally
+        }];
+        let remote = base.clone(); // unchanged remotely
+
+        let (merged, conflicts) = merge_boards(&base, &local, &remote);
+        assert!(conflicts.is_empty());
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].cards, vec![card(1, "a")]);
+    }
+}
@@ -0,0 +1,103 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use arc_swap::ArcSwap;
+use once_cell::sync::Lazy;
+
+use crate::app::kanban::Board;
+use crate::io::handler::get_save_dir;
+
+const RECOVERY_FILE_PREFIX: &str = "recovery_";
+
+/// Latest known board state, updated on a steady cadence from async context so
+/// that the (synchronous, non-async) panic hook can read it without needing to
+/// lock the tokio mutex `App` itself is guarded by.
+static BOARD_SNAPSHOT: Lazy<ArcSwap<Vec<Board>>> = Lazy::new(|| ArcSwap::from_pointee(Vec::new()));
+
+/// Guards against a panic occurring while we're still writing the emergency
+/// save for an earlier panic: the second panic's save is skipped so it can't
+/// mask or corrupt the first one.
+static SAVING: AtomicBool = AtomicBool::new(false);
+
+/// Refreshes the snapshot the panic hook will fall back to. Called periodically
+/// from the main loop (outside of the panic hook, which cannot safely await).
+pub fn update_snapshot(boards: &[Board]) {
+    BOARD_SNAPSHOT.store(Arc::new(boards.to_vec()));
+}
+
+/// Writes the most recent board snapshot to a timestamped recovery file in the
+/// data directory. Safe to call from the panic hook: it touches no async
+/// primitives and is a no-op if a save is already in flight or there's nothing
+/// to save.
+pub fn emergency_save() {
+    if SAVING.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    let boards = BOARD_SNAPSHOT.load();
+    if !boards.is_empty() {
+        if let Ok(json) = serde_json::to_string_pretty(boards.as_ref()) {
+            let _ = std::fs::write(recovery_file_path(), json);
+        }
+    }
+
+    SAVING.store(false, Ordering::SeqCst);
+}
+
+fn recovery_file_path() -> PathBuf {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let mut path = get_save_dir();
+    path.push(format!("{}{}.json", RECOVERY_FILE_PREFIX, now.as_secs()));
+    path
+}
+
+/// Returns the most recent recovery file left behind by a crash, if any, so
+/// `do_initialize` can offer to restore it.
+pub fn pending_recovery_file() -> Option<PathBuf> {
+    let save_dir = get_save_dir();
+    let entries = std::fs::read_dir(save_dir).ok()?;
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(RECOVERY_FILE_PREFIX))
+        })
+        .max_by_key(|path| {
+            std::fs::metadata(path)
+                .and_then(|meta| meta.modified())
+                .unwrap_or(UNIX_EPOCH)
+        })
+}
+
+/// Parses the boards out of a recovery file written by `emergency_save`.
+pub fn load_recovery_boards(path: &Path) -> eyre::Result<Vec<Board>> {
+    let contents = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&contents)?)
+}
+
+/// Deletes every recovery file in the data directory. Called once the user has
+/// accepted or declined the prompt `pending_recovery_file` raised, so a
+/// restored (or explicitly dismissed) crash doesn't keep resurfacing the same
+/// prompt on every future startup.
+pub fn discard_recovery_files() -> std::io::Result<()> {
+    let save_dir = get_save_dir();
+    let Ok(entries) = std::fs::read_dir(save_dir) else {
+        return Ok(());
+    };
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let is_recovery_file = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with(RECOVERY_FILE_PREFIX));
+        if is_recovery_file {
+            std::fs::remove_file(&path)?;
+        }
+    }
+    Ok(())
+}
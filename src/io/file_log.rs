@@ -0,0 +1,137 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use log::{error, LevelFilter};
+
+use crate::io::handler::get_save_dir;
+
+const LOG_FILE_NAME: &str = "rust_kanban.log";
+const LOG_LEVEL_ENV_VAR: &str = "RUST_KANBAN_LOG_LEVEL";
+/// Roll the log over to `rust_kanban.log.1` once it crosses this size, so a long
+/// session doesn't grow the file without bound.
+const MAX_LOG_BYTES: u64 = 5 * 1024 * 1024;
+/// How often the drain task checks `tui_logger`'s buffer for new records.
+const DRAIN_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Appends the records `tui_logger` has captured to a rotating file in the data
+/// directory, so a crash can be debugged after the fact instead of only showing
+/// up in the in-app log view, which only ever holds what's currently in memory.
+///
+/// This does *not* install itself as the global `log` logger — `tui_logger`
+/// already owns that slot (via `tui_logger::init_logger`, called once at
+/// startup), and `log` only allows one global logger per process. Instead,
+/// `run` periodically drains `tui_logger`'s own record buffer through its own
+/// `Drain` handle, which leaves the in-app log view (fed from that same
+/// buffer) untouched.
+pub struct RotatingFileLogger {
+    file: Mutex<File>,
+    path: PathBuf,
+}
+
+impl RotatingFileLogger {
+    /// Opens (creating and rotating if necessary) the log file in the data
+    /// directory. `RUST_KANBAN_LOG_LEVEL`, if set, overrides the level
+    /// `tui_logger` captures at; it is not a separate filter on top of that.
+    pub fn init() -> std::io::Result<Self> {
+        if let Some(level) = std::env::var(LOG_LEVEL_ENV_VAR)
+            .ok()
+            .and_then(|s| LevelFilter::from_str(&s).ok())
+        {
+            tui_logger::set_default_level(level);
+        }
+
+        let mut path = get_save_dir();
+        fs::create_dir_all(&path)?;
+        path.push(LOG_FILE_NAME);
+        rotate_if_oversized(&path)?;
+
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        Ok(RotatingFileLogger {
+            file: Mutex::new(file),
+            path,
+        })
+    }
+
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    fn append(&self, line: &str) {
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.write_all(line.as_bytes());
+            let _ = file.flush();
+        }
+    }
+
+    /// Drains whatever `tui_logger` has buffered since the last call and
+    /// appends it to the file.
+    fn drain_once(&self, drain: &mut tui_logger::Drain) {
+        for record in drain.drain() {
+            self.append(&format!(
+                "[{}] {:<5} {}: {}\n",
+                record.timestamp.format("%Y-%m-%dT%H:%M:%S%.3f"),
+                record.level,
+                record.target,
+                record.msg
+            ));
+        }
+    }
+
+    /// Rotates the file in place if it's crossed `MAX_LOG_BYTES`. Unlike the
+    /// startup-time `rotate_if_oversized`, this runs against an already-open
+    /// handle: renaming a path doesn't affect a file descriptor already open
+    /// on it, so a rotation mid-session has to drop the old handle and reopen
+    /// a fresh one at the (now-empty) path, not just rename underneath it.
+    fn rotate_if_needed(&self) {
+        let Ok(mut file) = self.file.lock() else {
+            return;
+        };
+        let oversized = file
+            .metadata()
+            .map(|metadata| metadata.len() >= MAX_LOG_BYTES)
+            .unwrap_or(false);
+        if !oversized {
+            return;
+        }
+        let _ = file.flush();
+
+        let mut rolled = self.path.clone();
+        rolled.set_extension("log.1");
+        if let Err(err) = fs::rename(&self.path, &rolled) {
+            error!("Cannot rotate log file: {:?}", err);
+            return;
+        }
+        match OpenOptions::new().create(true).append(true).open(&self.path) {
+            Ok(new_file) => *file = new_file,
+            Err(err) => error!("Cannot reopen log file after rotation: {:?}", err),
+        }
+    }
+
+    /// Runs the drain loop until the process exits. Spawned once alongside the
+    /// other background tasks in `main`, separately from `tui_logger::init_logger`
+    /// which is what actually registers with the `log` crate.
+    pub async fn run(self) {
+        let mut drain = tui_logger::Drain::new();
+        loop {
+            self.rotate_if_needed();
+            self.drain_once(&mut drain);
+            tokio::time::sleep(DRAIN_INTERVAL).await;
+        }
+    }
+}
+
+fn rotate_if_oversized(path: &PathBuf) -> std::io::Result<()> {
+    let Ok(metadata) = fs::metadata(path) else {
+        return Ok(());
+    };
+    if metadata.len() < MAX_LOG_BYTES {
+        return Ok(());
+    }
+    let mut rolled = path.clone();
+    rolled.set_extension("log.1");
+    fs::rename(path, rolled)
+}
@@ -1,3 +1,4 @@
+use std::sync::atomic::Ordering;
 use std::{sync::Arc, path::PathBuf};
 use crate::app::kanban::Board;
 use std::env;
@@ -6,8 +7,12 @@ use crate::constants::{
     CONFIG_FILE_NAME, SAVE_DIR_NAME
 };
 use crate::app::AppConfig;
-use crate::io::data_handler::{reset_config, save_kanban_state_locally};
-use eyre::Result;
+use crate::inputs::keybindings::KeybindConfig;
+use crate::io::cloud::{cloud_config, get_cloud_boards, save_cloud_boards, SyncResult};
+use crate::io::data_handler::{get_config, reset_config, save_kanban_state_locally};
+use crate::io::emergency::{discard_recovery_files, load_recovery_boards, pending_recovery_file};
+use crate::io::workers::{SharedInterval, WorkerRegistry};
+use eyre::{eyre, Result};
 use log::{error, info, debug};
 
 use super::IoEvent;
@@ -17,11 +22,21 @@ use crate::app::App;
 /// In the IO thread, we handle IO event without blocking the UI thread
 pub struct IoAsyncHandler {
     app: Arc<tokio::sync::Mutex<App>>,
+    workers: WorkerRegistry,
+    autosave_interval: SharedInterval,
 }
 
 impl IoAsyncHandler {
-    pub fn new(app: Arc<tokio::sync::Mutex<App>>) -> Self {
-        Self { app }
+    pub fn new(
+        app: Arc<tokio::sync::Mutex<App>>,
+        workers: WorkerRegistry,
+        autosave_interval: SharedInterval,
+    ) -> Self {
+        Self {
+            app,
+            workers,
+            autosave_interval,
+        }
     }
 
     /// We could be async here
@@ -32,6 +47,10 @@ impl IoAsyncHandler {
             IoEvent::GetCloudData => self.get_cloud_save().await,
             IoEvent::Reset => self.reset_config().await,
             IoEvent::SaveLocalData => self.save_local_data().await,
+            IoEvent::ListWorkers => self.list_workers().await,
+            IoEvent::SaveCloudData => self.save_cloud_data().await,
+            IoEvent::SetAutosaveInterval(seconds) => self.set_autosave_interval(seconds).await,
+            IoEvent::DismissPendingRecovery => self.dismiss_pending_recovery().await,
         };
 
         if let Err(err) = result {
@@ -52,8 +71,19 @@ impl IoAsyncHandler {
         if !prepare_save_dir() {
             error!("Cannot create save directory");
         }
+        migrate_legacy_savefiles();
         app.boards = prepare_boards();
         debug!("Boards: {:?}", app.boards);
+        if let Some(recovery_file) = pending_recovery_file() {
+            match load_recovery_boards(&recovery_file) {
+                Ok(boards) => {
+                    info!("Found a crash recovery file at {:?}", recovery_file);
+                    app.set_pending_recovery(recovery_file, boards);
+                }
+                Err(err) => error!("Found a recovery file but couldn't read it: {:?}", err),
+            }
+        }
+        app.keybinds = get_config().keybinds.compile();
         app.initialized(); // we could update the app state
         info!("👍 Application initialized");
         Ok(())
@@ -69,9 +99,50 @@ impl IoAsyncHandler {
 
     async fn get_cloud_save(&mut self) -> Result<()> {
         info!("🚀 Getting cloud save");
+        let app_config = get_config();
+        let cloud_config = match cloud_config(&app_config) {
+            Some(config) => config,
+            None => {
+                info!("Cloud sync isn't configured, leaving local state intact");
+                return Ok(());
+            }
+        };
+
+        let local_version = highest_local_version();
         let mut app = self.app.lock().await;
-        app.set_boards(vec![]);
-        info!("👍 Cloud save loaded");
+        match get_cloud_boards(cloud_config, local_version, &app.boards).await {
+            Ok(SyncResult::Clean(boards)) => {
+                app.set_boards(boards);
+                info!("👍 Cloud save loaded");
+            }
+            Ok(SyncResult::Conflicted(merged, conflicts)) => {
+                app.set_boards(merged);
+                info!(
+                    "👍 Cloud save merged with {} unresolved conflict(s)",
+                    conflicts.len()
+                );
+                app.set_sync_conflicts(conflicts);
+            }
+            Err(err) => {
+                error!("Cannot reach cloud backend, keeping local state: {:?}", err);
+            }
+        }
+        Ok(())
+    }
+
+    async fn save_cloud_data(&mut self) -> Result<()> {
+        info!("🚀 Saving to cloud");
+        let app_config = get_config();
+        let cloud_config = cloud_config(&app_config)
+            .ok_or_else(|| eyre!("Cloud sync isn't configured"))?;
+
+        let app = self.app.lock().await;
+        let next_version = highest_local_version() + 1;
+
+        match save_cloud_boards(cloud_config, next_version, &app.boards).await {
+            Ok(_) => info!("👍 Boards uploaded to cloud"),
+            Err(err) => error!("Cannot upload boards to cloud: {:?}", err),
+        }
         Ok(())
     }
 
@@ -93,21 +164,102 @@ impl IoAsyncHandler {
         }
         Ok(())
     }
+
+    async fn list_workers(&mut self) -> Result<()> {
+        info!("🚀 Listing background workers");
+        let mut app = self.app.lock().await;
+        app.set_worker_statuses(self.workers.lock().await.clone());
+        info!("👍 Worker list updated");
+        Ok(())
+    }
+
+    /// Updates the autosave worker's tick interval in place; it picks this up
+    /// on its next tick without needing a restart.
+    async fn set_autosave_interval(&mut self, seconds: u64) -> Result<()> {
+        self.autosave_interval.store(seconds, Ordering::Relaxed);
+        info!("👍 Autosave interval updated to {}s", seconds);
+        Ok(())
+    }
+
+    /// Clears `App::pending_recovery` and deletes the recovery file(s) on
+    /// disk, whether the user accepted or declined the restore prompt.
+    async fn dismiss_pending_recovery(&mut self) -> Result<()> {
+        info!("🚀 Dismissing crash recovery prompt");
+        discard_recovery_files()?;
+        let mut app = self.app.lock().await;
+        app.clear_pending_recovery();
+        info!("👍 Recovery files cleared");
+        Ok(())
+    }
 }
 
+const CONFIG_DIR_ENV_VAR: &str = "RUST_KANBAN_CONFIG";
+const DATA_DIR_ENV_VAR: &str = "RUST_KANBAN_DATA";
+
+/// The directory the config file lives in: `$RUST_KANBAN_CONFIG` if set, otherwise
+/// the platform's config base dir (e.g. `~/.config` on Linux) plus `CONFIG_DIR_NAME`.
 pub(crate) fn get_config_dir() -> PathBuf {
-    let mut config_dir = home::home_dir().unwrap();
-    config_dir.push(".config");
+    if let Some(override_dir) = env::var_os(CONFIG_DIR_ENV_VAR) {
+        return PathBuf::from(override_dir);
+    }
+    let mut config_dir = dirs::config_dir().expect("could not determine config directory");
     config_dir.push(CONFIG_DIR_NAME);
     config_dir
 }
 
+/// The directory savefiles live in: `$RUST_KANBAN_DATA` if set, otherwise the
+/// platform's data base dir (e.g. `~/.local/share` on Linux) plus `SAVE_DIR_NAME`.
+///
+/// This used to be `env::temp_dir()`, which meant savefiles didn't survive a
+/// reboot; `do_initialize` migrates any savefiles found there on first run.
 pub(crate) fn get_save_dir() -> PathBuf {
+    if let Some(override_dir) = env::var_os(DATA_DIR_ENV_VAR) {
+        return PathBuf::from(override_dir);
+    }
+    let mut save_dir = dirs::data_dir().expect("could not determine data directory");
+    save_dir.push(SAVE_DIR_NAME);
+    save_dir
+}
+
+fn legacy_save_dir() -> PathBuf {
     let mut save_dir = env::temp_dir();
     save_dir.push(SAVE_DIR_NAME);
     save_dir
 }
 
+/// One-time migration: if savefiles exist in the old `env::temp_dir()` location,
+/// move them into the new persistent data directory before `prepare_boards` reads
+/// from it. A no-op once the legacy directory is empty or gone.
+fn migrate_legacy_savefiles() {
+    let legacy_dir = legacy_save_dir();
+    if !legacy_dir.exists() {
+        return;
+    }
+    let save_dir = get_save_dir();
+    let entries = match std::fs::read_dir(&legacy_dir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            error!("Cannot read legacy save directory: {:?}", err);
+            return;
+        }
+    };
+    for entry in entries.flatten() {
+        let src = entry.path();
+        let dest = save_dir.join(entry.file_name());
+        if std::fs::rename(&src, &dest).is_ok() {
+            info!("👍 Migrated legacy savefile to {:?}", dest);
+            continue;
+        }
+        // `rename` fails across filesystem boundaries (e.g. a tmpfs `/tmp`
+        // against a persistent home dir — the common case this migration
+        // exists for), so fall back to a copy instead of giving up.
+        match std::fs::copy(&src, &dest).and_then(|_| std::fs::remove_file(&src)) {
+            Ok(_) => info!("👍 Migrated legacy savefile to {:?}", dest),
+            Err(err) => error!("Cannot migrate legacy savefile {:?}: {:?}", src, err),
+        }
+    }
+}
+
 fn prepare_config_dir() -> bool {
     let config_dir = get_config_dir();
     if !config_dir.exists() {
@@ -132,6 +284,16 @@ fn prepare_save_dir() -> bool {
     true
 }
 
+/// The highest version number among the local savefiles, or `0` if there are
+/// none yet.
+fn highest_local_version() -> u32 {
+    get_available_local_savefiles()
+        .iter()
+        .filter_map(|v| v.trim_start_matches('v').parse::<u32>().ok())
+        .max()
+        .unwrap_or(0)
+}
+
 fn prepare_boards () -> Vec<Board> {
     let local_save_files = get_available_local_savefiles();
     let fall_back_version = "1".to_string();
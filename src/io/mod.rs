@@ -0,0 +1,25 @@
+pub mod aws_sigv4;
+pub mod cloud;
+pub mod emergency;
+pub mod file_log;
+pub mod handler;
+pub mod workers;
+
+/// Events sent from the UI/main threads to the IO thread for out-of-band work
+/// (disk, network) that shouldn't block rendering.
+#[derive(Clone, Debug)]
+pub enum IoEvent {
+    Initialize,
+    GetLocalData,
+    GetCloudData,
+    Reset,
+    SaveLocalData,
+    ListWorkers,
+    SaveCloudData,
+    /// Changes the autosave worker's tick interval (in seconds) at runtime.
+    SetAutosaveInterval(u64),
+    /// The user accepted or declined the crash recovery prompt raised by
+    /// `App::pending_recovery`: clear it and delete the recovery file(s) on
+    /// disk so the same prompt doesn't resurface on the next startup.
+    DismissPendingRecovery,
+}
@@ -0,0 +1,129 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
+use log::{error, info};
+use tokio::sync::Mutex;
+use tokio::time::sleep;
+
+use crate::app::kanban::Board;
+use crate::app::App;
+use crate::io::data_handler::save_kanban_state_locally;
+
+/// Current state of a background worker, as reported in the worker registry.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum WorkerState {
+    Active,
+    Idle,
+    Dead,
+}
+
+/// A single entry in the worker registry: who the worker is, what it's doing now,
+/// and when it last ran.
+#[derive(Clone, Debug)]
+pub struct WorkerStatus {
+    pub name: String,
+    pub state: WorkerState,
+    pub last_run: Option<SystemTime>,
+}
+
+/// Shared, lock-protected registry of every background worker's status, so
+/// `IoEvent::ListWorkers` can report it to the UI without owning the workers
+/// themselves.
+pub type WorkerRegistry = Arc<Mutex<Vec<WorkerStatus>>>;
+
+/// A worker's tick interval, in seconds, shared between the worker loop and
+/// whatever can adjust it at runtime (e.g. `IoEvent::SetAutosaveInterval`).
+/// Reading it fresh every tick instead of closing over a fixed `Duration` is
+/// what makes the interval actually changeable while the worker is running.
+pub type SharedInterval = Arc<AtomicU64>;
+
+/// A background task that performs one step of work per tick and reports its
+/// state back into a `WorkerRegistry`.
+#[async_trait]
+pub trait Worker {
+    fn name(&self) -> &str;
+
+    /// Performs one unit of work and returns the resulting state.
+    async fn work(&mut self) -> WorkerState;
+}
+
+/// Runs `worker` in a loop, sleeping `interval` (read fresh each tick, so it
+/// can change between ticks) between ticks, and keeps its entry in `registry`
+/// up to date. Stops looping once `work` reports `Dead`.
+pub async fn run_worker(mut worker: impl Worker, registry: WorkerRegistry, interval: SharedInterval) {
+    {
+        let mut guard = registry.lock().await;
+        guard.push(WorkerStatus {
+            name: worker.name().to_string(),
+            state: WorkerState::Idle,
+            last_run: None,
+        });
+    }
+
+    loop {
+        let state = worker.work().await;
+        let now = SystemTime::now();
+        {
+            let mut guard = registry.lock().await;
+            if let Some(status) = guard.iter_mut().find(|s| s.name == worker.name()) {
+                status.state = state;
+                status.last_run = Some(now);
+            }
+        }
+        if state == WorkerState::Dead {
+            break;
+        }
+        sleep(Duration::from_secs(interval.load(Ordering::Relaxed))).await;
+    }
+}
+
+/// Periodically diffs `app.boards` against the last snapshot it saved and, when
+/// something changed, persists it via `save_kanban_state_locally`. Leaves boards
+/// untouched (and reports `Idle`) when nothing is dirty, so unchanged state
+/// doesn't churn out new save-file versions. The first tick has no saved
+/// snapshot to compare against, so it always saves whatever `app.boards` holds.
+pub struct AutosaveWorker {
+    app: Arc<Mutex<App>>,
+    last_saved: Option<Vec<Board>>,
+}
+
+impl AutosaveWorker {
+    pub fn new(app: Arc<Mutex<App>>) -> Self {
+        Self {
+            app,
+            last_saved: None,
+        }
+    }
+}
+
+#[async_trait]
+impl Worker for AutosaveWorker {
+    fn name(&self) -> &str {
+        "autosave"
+    }
+
+    async fn work(&mut self) -> WorkerState {
+        let boards = {
+            let app = self.app.lock().await;
+            app.boards.clone()
+        };
+
+        if self.last_saved.as_ref() == Some(&boards) {
+            return WorkerState::Idle;
+        }
+
+        match save_kanban_state_locally(boards.clone()) {
+            Ok(_) => {
+                info!("👍 Autosave worker persisted dirty board state");
+                self.last_saved = Some(boards);
+                WorkerState::Active
+            }
+            Err(err) => {
+                error!("Autosave worker failed to save board state: {:?}", err);
+                WorkerState::Dead
+            }
+        }
+    }
+}
@@ -7,12 +7,21 @@ use tui::backend::CrosstermBackend;
 use tui::Terminal;
 
 use eyre::Result;
-use log::LevelFilter;
 use rust_kanban::start_ui;
 use rust_kanban::{
     app::App,
-    io::{handler::IoAsyncHandler, IoEvent},
+    io::{
+        emergency,
+        file_log::RotatingFileLogger,
+        handler::IoAsyncHandler,
+        workers::{run_worker, AutosaveWorker},
+        IoEvent,
+    },
 };
+use std::time::Duration;
+
+/// How often the main loop refreshes the panic hook's board snapshot.
+const SNAPSHOT_INTERVAL: Duration = Duration::from_millis(250);
 
 extern crate savefile_derive;
 #[derive(Parser)]
@@ -28,6 +37,9 @@ async fn main() -> Result<()> {
     // Handling Panic when terminal is in raw mode
     let default_panic = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |info| {
+        // Best-effort: dump the last known board state before we do anything
+        // else, so a panic never silently loses unsaved work.
+        emergency::emergency_save();
         _ = terminal::disable_raw_mode();
         let execute_result = execute!(stdout(), DisableMouseCapture);
         if let Err(e) = execute_result {
@@ -52,18 +64,61 @@ async fn main() -> Result<()> {
     let main_app_instance = Arc::new(tokio::sync::Mutex::new(App::new(sync_io_tx.clone())));
     let app_widget_manager_instance = Arc::clone(&main_app_instance);
     let app_ui_instance = Arc::clone(&main_app_instance);
-    // Configure log
-    tui_logger::init_logger(LevelFilter::Debug).unwrap();
-    tui_logger::set_default_level(log::LevelFilter::Debug);
+    let app_autosave_instance = Arc::clone(&main_app_instance);
+    let app_snapshot_instance = Arc::clone(&main_app_instance);
+    // tui_logger owns the global `log` logger, since the in-app log view is a
+    // widget reading straight out of its buffer.
+    tui_logger::init_logger(tui_logger::LevelFilter::Debug).expect("failed to initialize logger");
+    tui_logger::set_default_level(tui_logger::LevelFilter::Debug);
+
+    // Persistent, rotating file logging: drains the same tui_logger buffer on a
+    // timer and appends it to disk, so a crash can be debugged after the fact
+    // instead of only showing up in the in-memory log view.
+    let file_logger = RotatingFileLogger::init().expect("failed to initialize file logger");
+    tokio::spawn(file_logger.run());
+
+    let worker_registry = std::sync::Arc::new(tokio::sync::Mutex::new(Vec::new()));
+    let autosave_worker_registry = Arc::clone(&worker_registry);
+
+    // Shared, runtime-adjustable autosave interval: starts from AppConfig, but
+    // can be changed later via `IoEvent::SetAutosaveInterval` without
+    // restarting the worker.
+    let autosave_interval = Arc::new(std::sync::atomic::AtomicU64::new(
+        rust_kanban::io::data_handler::get_config().autosave_interval_seconds,
+    ));
+    let autosave_worker_interval = Arc::clone(&autosave_interval);
 
     // Handle IO in a specifc thread
     tokio::spawn(async move {
-        let mut handler = IoAsyncHandler::new(main_app_instance);
+        let mut handler = IoAsyncHandler::new(main_app_instance, worker_registry, autosave_interval);
         while let Some(io_event) = sync_io_rx.recv().await {
             handler.handle_io_event(io_event).await;
         }
     });
 
+    // Background autosave: persists boards whenever they're dirty, at an interval
+    // read from AppConfig and adjustable at runtime via `IoEvent::SetAutosaveInterval`.
+    tokio::spawn(async move {
+        run_worker(
+            AutosaveWorker::new(app_autosave_instance),
+            autosave_worker_registry,
+            autosave_worker_interval,
+        )
+        .await;
+    });
+
+    // Keep the panic hook's emergency-save snapshot fresh. This runs outside the
+    // panic hook itself since the hook can't safely await the tokio mutex.
+    tokio::spawn(async move {
+        loop {
+            {
+                let app = app_snapshot_instance.lock().await;
+                emergency::update_snapshot(&app.boards);
+            }
+            tokio::time::sleep(SNAPSHOT_INTERVAL).await;
+        }
+    });
+
     tokio::spawn(async move {
         let mut widget_manager =
             rust_kanban::ui::widgets::WidgetManager::new(app_widget_manager_instance);